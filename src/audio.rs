@@ -0,0 +1,97 @@
+/// Events emitted by the simulation core (eating, wall bounces, `Divider`
+/// splits, Game-of-Life births/deaths) so playback can react to them
+/// without `apply_interactions`/`apply_game_of_life_rules` calling into
+/// the audio layer directly.
+#[derive(Debug, Clone, Copy)]
+// Payload fields are only read by the real `audio` backend below; the
+// no-op backend ignores them by design, which would otherwise trip
+// dead_code when the `audio` feature is disabled.
+#[cfg_attr(not(feature = "audio"), allow(dead_code))]
+pub enum SimEvent {
+    Eaten { eater_mass: f32, eaten_mass: f32 },
+    WallBounce { mass: f32, impact_speed: f32 },
+    Split { mass: f32 },
+    GameOfLifeBirth,
+    GameOfLifeDeath,
+}
+
+#[cfg(feature = "audio")]
+mod backend {
+    use super::SimEvent;
+    use macroquad::audio::{load_sound, play_sound, PlaySoundParams, Sound};
+
+    /// Loads short OGG samples and plays them in response to `SimEvent`s,
+    /// with volume scaled by the dots' masses and impact speed so large
+    /// collisions sound heavier. Pitch is not scaled: quad-snd's
+    /// `PlaySoundParams` (macroquad's audio backend) only exposes `looped`
+    /// and `volume` per call, with no pitch/rate knob to drive from impact
+    /// size, so "heavier" is conveyed through volume alone.
+    pub struct Audio {
+        eat: Option<Sound>,
+        bounce: Option<Sound>,
+        split: Option<Sound>,
+        birth: Option<Sound>,
+        death: Option<Sound>,
+    }
+
+    impl Audio {
+        /// Silent placeholder with nothing loaded yet; used until `load`
+        /// finishes so construction doesn't need to be async.
+        pub fn silent() -> Self {
+            Self { eat: None, bounce: None, split: None, birth: None, death: None }
+        }
+
+        pub async fn load(dir: &str) -> Self {
+            Self {
+                eat: load_sound(&format!("{dir}/eat.ogg")).await.ok(),
+                bounce: load_sound(&format!("{dir}/bounce.ogg")).await.ok(),
+                split: load_sound(&format!("{dir}/split.ogg")).await.ok(),
+                birth: load_sound(&format!("{dir}/birth.ogg")).await.ok(),
+                death: load_sound(&format!("{dir}/death.ogg")).await.ok(),
+            }
+        }
+
+        pub fn handle(&self, event: SimEvent) {
+            match event {
+                SimEvent::Eaten { eater_mass, eaten_mass } => {
+                    self.play(&self.eat, (eater_mass + eaten_mass) * 0.05)
+                }
+                SimEvent::WallBounce { mass, impact_speed } => {
+                    self.play(&self.bounce, mass * 0.05 * impact_speed.max(0.1))
+                }
+                SimEvent::Split { mass } => self.play(&self.split, mass * 0.05),
+                SimEvent::GameOfLifeBirth => self.play(&self.birth, 0.5),
+                SimEvent::GameOfLifeDeath => self.play(&self.death, 0.5),
+            }
+        }
+
+        fn play(&self, sound: &Option<Sound>, volume: f32) {
+            let Some(sound) = sound else { return };
+            play_sound(sound, PlaySoundParams { looped: false, volume: volume.clamp(0.1, 1.0) });
+        }
+    }
+}
+
+#[cfg(not(feature = "audio"))]
+mod backend {
+    use super::SimEvent;
+
+    /// No-op stand-in for builds with the `audio` feature disabled
+    /// (headless runs, CI), so the simulation core can always emit
+    /// `SimEvent`s without caring whether playback is compiled in.
+    pub struct Audio;
+
+    impl Audio {
+        pub fn silent() -> Self {
+            Self
+        }
+
+        pub async fn load(_dir: &str) -> Self {
+            Self
+        }
+
+        pub fn handle(&self, _event: SimEvent) {}
+    }
+}
+
+pub use backend::Audio;
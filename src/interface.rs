@@ -0,0 +1,78 @@
+use macroquad::prelude::*;
+
+/// Abstracts the rendering/input/timing surface the simulation needs, so
+/// the same `GameState` can run against a real window or headless (no-op
+/// rendering, synthetic timing) for scripted/regression runs.
+pub trait GameInterface {
+    fn clear(&mut self);
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color);
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color);
+    fn key_pressed(&self, key: KeyCode) -> bool;
+    fn mouse_position(&self) -> (f32, f32);
+    fn mouse_button_down(&self, button: MouseButton) -> bool;
+    fn delta_time(&self) -> f32;
+}
+
+/// Real implementation backed by macroquad's window/canvas.
+pub struct MacroquadInterface;
+
+impl GameInterface for MacroquadInterface {
+    fn clear(&mut self) {
+        clear_background(BLACK);
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        macroquad::shapes::draw_circle(x, y, radius, color);
+    }
+
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color) {
+        macroquad::text::draw_text(text, x, y, font_size, color);
+    }
+
+    fn key_pressed(&self, key: KeyCode) -> bool {
+        is_key_pressed(key)
+    }
+
+    fn mouse_position(&self) -> (f32, f32) {
+        macroquad::input::mouse_position()
+    }
+
+    fn mouse_button_down(&self, button: MouseButton) -> bool {
+        is_mouse_button_down(button)
+    }
+
+    fn delta_time(&self) -> f32 {
+        get_frame_time()
+    }
+}
+
+/// No-op implementation used for headless runs: nothing is drawn, no
+/// input ever fires, and `delta_time` returns a fixed step so frame-N
+/// behavior is reproducible regardless of wall-clock speed.
+pub struct HeadlessInterface {
+    pub fixed_dt: f32,
+}
+
+impl HeadlessInterface {
+    pub fn new(fixed_dt: f32) -> Self {
+        Self { fixed_dt }
+    }
+}
+
+impl GameInterface for HeadlessInterface {
+    fn clear(&mut self) {}
+    fn draw_circle(&mut self, _x: f32, _y: f32, _radius: f32, _color: Color) {}
+    fn draw_text(&mut self, _text: &str, _x: f32, _y: f32, _font_size: f32, _color: Color) {}
+    fn key_pressed(&self, _key: KeyCode) -> bool {
+        false
+    }
+    fn mouse_position(&self) -> (f32, f32) {
+        (0.0, 0.0)
+    }
+    fn mouse_button_down(&self, _button: MouseButton) -> bool {
+        false
+    }
+    fn delta_time(&self) -> f32 {
+        self.fixed_dt
+    }
+}
@@ -1,15 +1,40 @@
 use macroquad::prelude::*;
+use ::rand::rngs::StdRng;
 use ::rand::Rng as _;
+use ::rand::SeedableRng;
 use ::rand::thread_rng;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::fs;
 
+mod spatial_grid;
+use spatial_grid::SpatialGrid;
+mod neural;
+use neural::NN;
+mod population;
+use population::{Candidate, Population};
+mod scripting;
+use scripting::ScriptEngine;
+use rhai::Map as ScriptMap;
+mod interface;
+use interface::{GameInterface, HeadlessInterface, MacroquadInterface};
+mod audio;
+use audio::{Audio, SimEvent};
+mod pathfinding;
+use pathfinding::{NavGrid, NAV_CELL_SIZE};
+
 // Constants
 const SCREEN_WIDTH: f32 = 1280.0;
 const SCREEN_HEIGHT: f32 = 720.0;
 const MAX_DOTS: usize = 1000;
 const DOT_RADIUS: f32 = 5.0;
 const INTERACTION_RADIUS: f32 = 50.0;
+/// Dots sharing a nav cell at or above this count mark it blocked, so
+/// `Chaser`/`Predator` dots route around dense clusters.
+const CLUSTER_DENSITY_BLOCK: usize = 5;
+/// How many frames a `Chaser`/`Predator` dot's A* path is reused before
+/// being recomputed (sooner if it crosses into a new target cell).
+const NAV_RECOMPUTE_FRAMES: u32 = 15;
 
 // Dot types with different behaviors
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -28,6 +53,9 @@ enum DotType {
     Social,       // Prefers company of similar dots
     Grower,       // Grows over time
     Divider,      // Splits into multiple dots
+    Neural,       // Steered by an evolving neural network
+    Obstacle,     // Static/immovable; blocks navigation for Chaser/Predator
+    Scripted(usize), // Behavior defined by a loaded .rhai script, indexed into ScriptEngine::types
 }
 
 impl DotType {
@@ -47,6 +75,12 @@ impl DotType {
             DotType::Social => LIME,
             DotType::Grower => BROWN,
             DotType::Divider => Color::new(0.0, 1.0, 1.0, 1.0),
+            DotType::Neural => GREEN,
+            DotType::Obstacle => DARKGRAY,
+            // Actual color comes from the script and is cached on the dot
+            // at creation time (see `Dot::script_color`); this is only a
+            // fallback for contexts without a dot instance to read from.
+            DotType::Scripted(_) => WHITE,
         }
     }
 
@@ -66,6 +100,8 @@ impl DotType {
             DotType::Social,
             DotType::Grower,
             DotType::Divider,
+            DotType::Neural,
+            DotType::Obstacle,
         ]
     }
 }
@@ -82,6 +118,8 @@ struct DotConfig {
     eating_radius: f32,
     growth_rate: f32,
     divide_size: f32,
+    mut_rate: f32,
+    generation_length: u32,
 }
 
 impl Default for DotConfig {
@@ -96,6 +134,8 @@ impl Default for DotConfig {
             eating_radius: 15.0,
             growth_rate: 0.01,
             divide_size: 20.0,
+            mut_rate: 0.1,
+            generation_length: 1800,
         }
     }
 }
@@ -113,6 +153,15 @@ struct Dot {
     alive: bool,
     age: f32,
     energy: f32,
+    brain: Option<NN>,
+    script_color: Option<Color>,
+    /// Cached unit direction toward this dot's next A* waypoint, refreshed
+    /// by `GameState::update_navigation`. Only meaningful for `Chaser`/
+    /// `Predator` dots.
+    nav_direction: Vec2,
+    nav_path: Vec<Vec2>,
+    nav_target_cell: Option<(usize, usize)>,
+    nav_recompute_in: u32,
 }
 
 impl Dot {
@@ -128,11 +177,18 @@ impl Dot {
             alive: true,
             age: 0.0,
             energy: 100.0,
+            brain: None,
+            script_color: None,
+            nav_direction: vec2(0.0, 0.0),
+            nav_path: Vec::new(),
+            nav_target_cell: None,
+            nav_recompute_in: 0,
         }
     }
 
-    fn random(dot_type: DotType) -> Self {
-        let mut rng = thread_rng();
+    /// Builds a dot at a random position/velocity drawn from `rng`, so a
+    /// seeded `StdRng` makes a whole seed run reproducible.
+    fn random(dot_type: DotType, rng: &mut impl ::rand::Rng) -> Self {
         let mut dot = Self::new(
             rng.gen_range(50.0..SCREEN_WIDTH - 50.0),
             rng.gen_range(50.0..SCREEN_HEIGHT - 50.0),
@@ -142,16 +198,41 @@ impl Dot {
             rng.gen_range(-2.0..2.0),
             rng.gen_range(-2.0..2.0),
         );
+        if dot_type == DotType::Neural {
+            dot.brain = Some(NN::new(NN::default_topology(), rng));
+        }
+        dot
+    }
+
+    /// Spawns a `Neural` dot at a random position carrying a specific
+    /// (already-bred) brain, used when starting a new generation.
+    fn random_with_brain(brain: NN, rng: &mut impl ::rand::Rng) -> Self {
+        let mut dot = Self::random(DotType::Neural, rng);
+        dot.brain = Some(brain);
         dot
     }
 
+    /// The color to render this dot with: the script-declared color for
+    /// `Scripted` dots, otherwise the type's fixed color.
+    fn color(&self) -> Color {
+        self.script_color.unwrap_or_else(|| self.dot_type.color())
+    }
+
     fn apply_force(&mut self, force: Vec2) {
         self.acceleration += force / self.mass;
     }
 
-    fn update(&mut self, config: &DotConfig, dt: f32) {
+    fn update(&mut self, config: &DotConfig, dt: f32) -> Vec<SimEvent> {
+        let mut events = Vec::new();
+
         if !self.alive {
-            return;
+            return events;
+        }
+
+        // Obstacles are static/immovable: no physics, aging, or energy drain.
+        if self.dot_type == DotType::Obstacle {
+            self.acceleration = vec2(0.0, 0.0);
+            return events;
         }
 
         // Update velocity and position
@@ -171,23 +252,31 @@ impl Dot {
         // Bounce off walls
         if self.position.x < self.radius {
             self.position.x = self.radius;
+            let impact_speed = self.velocity.x.abs();
             self.velocity.x *= -config.bounce_damping;
             self.spin = self.velocity.y * 0.1;
+            events.push(SimEvent::WallBounce { mass: self.mass, impact_speed });
         }
         if self.position.x > SCREEN_WIDTH - self.radius {
             self.position.x = SCREEN_WIDTH - self.radius;
+            let impact_speed = self.velocity.x.abs();
             self.velocity.x *= -config.bounce_damping;
             self.spin = -self.velocity.y * 0.1;
+            events.push(SimEvent::WallBounce { mass: self.mass, impact_speed });
         }
         if self.position.y < self.radius {
             self.position.y = self.radius;
+            let impact_speed = self.velocity.y.abs();
             self.velocity.y *= -config.bounce_damping;
             self.spin = -self.velocity.x * 0.1;
+            events.push(SimEvent::WallBounce { mass: self.mass, impact_speed });
         }
         if self.position.y > SCREEN_HEIGHT - self.radius {
             self.position.y = SCREEN_HEIGHT - self.radius;
+            let impact_speed = self.velocity.y.abs();
             self.velocity.y *= -config.bounce_damping;
             self.spin = self.velocity.x * 0.1;
+            events.push(SimEvent::WallBounce { mass: self.mass, impact_speed });
         }
 
         // Update spin
@@ -213,28 +302,25 @@ impl Dot {
         if self.energy <= 0.0 {
             self.alive = false;
         }
+
+        events
     }
 
-    fn draw(&self, show_aura: bool) {
+    fn draw(&self, show_aura: bool, gfx: &mut dyn GameInterface) {
         if !self.alive {
             return;
         }
 
-        let color = self.dot_type.color();
+        let color = self.color();
 
         // Draw aura of influence
         if show_aura {
             let aura_color = Color::new(color.r, color.g, color.b, 0.1);
-            draw_circle(
-                self.position.x,
-                self.position.y,
-                INTERACTION_RADIUS,
-                aura_color,
-            );
+            gfx.draw_circle(self.position.x, self.position.y, INTERACTION_RADIUS, aura_color);
         }
 
         // Draw the dot
-        draw_circle(self.position.x, self.position.y, self.radius, color);
+        gfx.draw_circle(self.position.x, self.position.y, self.radius, color);
 
         // Draw velocity vector (for debugging)
         if show_aura {
@@ -265,6 +351,20 @@ impl Dot {
     }
 }
 
+/// Exposes the fields a `.rhai` script needs to see off a dot: position,
+/// velocity, radius, energy, and its type name as a string.
+fn dot_to_script_map(dot: &Dot) -> ScriptMap {
+    let mut map = ScriptMap::new();
+    map.insert("pos_x".into(), (dot.position.x as f64).into());
+    map.insert("pos_y".into(), (dot.position.y as f64).into());
+    map.insert("vel_x".into(), (dot.velocity.x as f64).into());
+    map.insert("vel_y".into(), (dot.velocity.y as f64).into());
+    map.insert("radius".into(), (dot.radius as f64).into());
+    map.insert("energy".into(), (dot.energy as f64).into());
+    map.insert("dot_type".into(), format!("{:?}", dot.dot_type).into());
+    map
+}
+
 // Game state
 struct GameState {
     dots: Vec<Dot>,
@@ -274,6 +374,10 @@ struct GameState {
     show_aura: bool,
     game_of_life_mode: bool,
     frame_count: u64,
+    population: Population,
+    scripts: ScriptEngine,
+    rng: RefCell<StdRng>,
+    audio: Audio,
 }
 
 impl GameState {
@@ -286,17 +390,67 @@ impl GameState {
             show_aura: true,
             game_of_life_mode: false,
             frame_count: 0,
+            population: Population::new(),
+            scripts: ScriptEngine::load_dir("scripts"),
+            rng: RefCell::new(StdRng::from_entropy()),
+            audio: Audio::silent(),
+        }
+    }
+
+    /// Builds a `GameState` whose dot RNG is seeded up front, for headless
+    /// runs that need the whole session reproducible from frame zero.
+    fn new_seeded(seed: u64) -> Self {
+        let mut state = Self::new();
+        state.rng = RefCell::new(StdRng::seed_from_u64(seed));
+        state
+    }
+
+    /// Repoints every `Scripted` dot, plus the current `selected_type`, at
+    /// its script's new index after `ScriptEngine::reload`, using the
+    /// name-keyed `old_index -> new_index` mapping it returns. A script
+    /// that's gone missing drops the dot back to `DotType::Classic` rather
+    /// than leaving it pointed at whatever now sits at its old index.
+    fn remap_scripted_types(&mut self, remap: &[Option<usize>]) {
+        for dot in &mut self.dots {
+            if let DotType::Scripted(old_index) = dot.dot_type {
+                dot.dot_type = match remap.get(old_index).copied().flatten() {
+                    Some(new_index) => DotType::Scripted(new_index),
+                    None => {
+                        dot.script_color = None;
+                        DotType::Classic
+                    }
+                };
+            }
+        }
+
+        if let DotType::Scripted(old_index) = self.selected_type {
+            self.selected_type = match remap.get(old_index).copied().flatten() {
+                Some(new_index) => DotType::Scripted(new_index),
+                None => DotType::Classic,
+            };
         }
     }
 
     fn add_dot(&mut self, x: f32, y: f32) {
-        if self.dots.len() < MAX_DOTS {
-            self.dots.push(Dot::new(x, y, self.selected_type));
+        if self.dots.len() >= MAX_DOTS {
+            return;
+        }
+
+        let mut dot = Dot::new(x, y, self.selected_type);
+        if let DotType::Scripted(index) = self.selected_type {
+            dot.script_color = self.scripts.types.get(index).map(|t| t.color);
         }
+        self.dots.push(dot);
     }
 
-    fn seed_random(&mut self, count: usize, _seed: Option<u64>) {
-        // Seed parameter available for future use with explicit seeding
+    /// Reseeds the simulation with `count` random dots. When `seed` is
+    /// `Some`, the dot RNG is reset to `StdRng::seed_from_u64(seed)` first,
+    /// so the resulting population (and everything downstream that draws
+    /// from this RNG) is fully reproducible from that seed.
+    fn seed_random(&mut self, count: usize, seed: Option<u64>) {
+        if let Some(seed) = seed {
+            self.rng = RefCell::new(StdRng::seed_from_u64(seed));
+        }
 
         self.dots.clear();
 
@@ -306,83 +460,109 @@ impl GameState {
             DotType::all_types()
         };
 
-        let mut rng = thread_rng();
+        let mut rng = self.rng.borrow_mut();
         for _ in 0..count {
             let dot_type = types[rng.gen_range(0..types.len())];
-            self.dots.push(Dot::random(dot_type));
+            self.dots.push(Dot::random(dot_type, &mut *rng));
         }
     }
 
-    fn apply_interactions(&mut self) {
+    /// Builds a `SpatialGrid` sized for this frame: cells are at least as
+    /// wide as the configured interaction radius, but grow to cover the
+    /// largest dot currently in play (a `Grower` dot can expand well past
+    /// the base interaction radius and still needs to match neighbors).
+    fn build_spatial_grid(&self) -> SpatialGrid {
+        let max_radius = self
+            .dots
+            .iter()
+            .filter(|d| d.alive)
+            .map(|d| d.radius)
+            .fold(self.config.interaction_radius, f32::max);
+
+        let mut grid = SpatialGrid::new(SCREEN_WIDTH, SCREEN_HEIGHT, max_radius);
+        let positions: Vec<(Vec2, f32)> = self
+            .dots
+            .iter()
+            .map(|d| (d.position, if d.alive { d.radius } else { 0.0 }))
+            .collect();
+        grid.rebuild(&positions);
+        grid
+    }
+
+    fn apply_interactions(&mut self) -> Vec<SimEvent> {
         let config = self.config.clone();
+        let grid = self.build_spatial_grid();
 
         // Collect interaction forces
         let mut forces: Vec<Vec2> = vec![vec2(0.0, 0.0); self.dots.len()];
         let mut to_remove: Vec<usize> = Vec::new();
         let mut to_add: Vec<Dot> = Vec::new();
+        let mut events: Vec<SimEvent> = Vec::new();
 
-        for i in 0..self.dots.len() {
-            if !self.dots[i].alive {
+        for (i, j) in grid.candidate_pairs() {
+            if !self.dots[i].alive || !self.dots[j].alive {
                 continue;
             }
 
-            for j in (i + 1)..self.dots.len() {
-                if !self.dots[j].alive {
-                    continue;
-                }
+            let distance = self.dots[i].distance_to(&self.dots[j]);
 
-                let distance = self.dots[i].distance_to(&self.dots[j]);
+            // Skip if too far
+            if distance > config.interaction_radius {
+                continue;
+            }
 
-                // Skip if too far
-                if distance > config.interaction_radius {
+            let direction = (self.dots[j].position - self.dots[i].position).normalize();
+
+            // Collision detection
+            let min_distance = self.dots[i].radius + self.dots[j].radius;
+            if distance < min_distance && distance > 0.1 {
+                // Check for eating
+                if self.dots[i].can_eat(&self.dots[j]) && distance < config.eating_radius {
+                    to_remove.push(j);
+                    events.push(SimEvent::Eaten {
+                        eater_mass: self.dots[i].mass,
+                        eaten_mass: self.dots[j].mass,
+                    });
+                    // Absorb energy and potentially grow
+                    if self.dots[i].dot_type == DotType::Absorber {
+                        self.dots[i].radius += self.dots[j].radius * 0.2;
+                        self.dots[i].mass = self.dots[i].radius * self.dots[i].radius;
+                    }
+                    self.dots[i].energy += self.dots[j].energy * 0.5;
                     continue;
-                }
-
-                let direction = (self.dots[j].position - self.dots[i].position).normalize();
-
-                // Collision detection
-                let min_distance = self.dots[i].radius + self.dots[j].radius;
-                if distance < min_distance && distance > 0.1 {
-                    // Check for eating
-                    if self.dots[i].can_eat(&self.dots[j]) && distance < config.eating_radius {
-                        to_remove.push(j);
-                        // Absorb energy and potentially grow
-                        if self.dots[i].dot_type == DotType::Absorber {
-                            self.dots[i].radius += self.dots[j].radius * 0.2;
-                            self.dots[i].mass = self.dots[i].radius * self.dots[i].radius;
-                        }
-                        self.dots[i].energy += self.dots[j].energy * 0.5;
-                        continue;
-                    } else if self.dots[j].can_eat(&self.dots[i]) && distance < config.eating_radius {
-                        to_remove.push(i);
-                        if self.dots[j].dot_type == DotType::Absorber {
-                            self.dots[j].radius += self.dots[i].radius * 0.2;
-                            self.dots[j].mass = self.dots[j].radius * self.dots[j].radius;
-                        }
-                        self.dots[j].energy += self.dots[i].energy * 0.5;
-                        continue;
+                } else if self.dots[j].can_eat(&self.dots[i]) && distance < config.eating_radius {
+                    to_remove.push(i);
+                    events.push(SimEvent::Eaten {
+                        eater_mass: self.dots[j].mass,
+                        eaten_mass: self.dots[i].mass,
+                    });
+                    if self.dots[j].dot_type == DotType::Absorber {
+                        self.dots[j].radius += self.dots[i].radius * 0.2;
+                        self.dots[j].mass = self.dots[j].radius * self.dots[j].radius;
                     }
+                    self.dots[j].energy += self.dots[i].energy * 0.5;
+                    continue;
+                }
 
-                    // Physical collision (unless ghost)
-                    if self.dots[i].dot_type != DotType::Ghost && self.dots[j].dot_type != DotType::Ghost {
-                        let overlap = min_distance - distance;
-                        let separation = direction * overlap * 0.5;
+                // Physical collision (unless ghost)
+                if self.dots[i].dot_type != DotType::Ghost && self.dots[j].dot_type != DotType::Ghost {
+                    let overlap = min_distance - distance;
+                    let separation = direction * overlap * 0.5;
 
-                        forces[i] -= separation;
-                        forces[j] += separation;
+                    forces[i] -= separation;
+                    forces[j] += separation;
 
-                        // Elastic collision with spin
-                        let relative_velocity = self.dots[i].velocity - self.dots[j].velocity;
-                        let impulse = direction * relative_velocity.dot(direction) * config.bounce_damping;
+                    // Elastic collision with spin
+                    let relative_velocity = self.dots[i].velocity - self.dots[j].velocity;
+                    let impulse = direction * relative_velocity.dot(direction) * config.bounce_damping;
 
-                        forces[i] -= impulse * self.dots[j].mass;
-                        forces[j] += impulse * self.dots[i].mass;
-                    }
+                    forces[i] -= impulse * self.dots[j].mass;
+                    forces[j] += impulse * self.dots[i].mass;
                 }
-
-                // Apply type-specific forces
-                self.apply_type_forces(i, j, distance, direction, &mut forces, &mut to_add);
             }
+
+            // Apply type-specific forces
+            self.apply_type_forces(i, j, distance, direction, &mut forces, &mut to_add, &mut events);
         }
 
         // Apply collected forces
@@ -410,16 +590,23 @@ impl GameState {
         if self.frame_count % 60 == 0 {
             self.dots.retain(|d| d.alive);
         }
+
+        events
     }
 
+    // `i`/`j`/`distance`/`direction` describe the interacting pair, while
+    // `forces`/`new_dots`/`events` are per-frame accumulators shared across
+    // every pair `apply_interactions` visits.
+    #[allow(clippy::too_many_arguments)]
     fn apply_type_forces(
         &self,
         i: usize,
         j: usize,
         distance: f32,
         direction: Vec2,
-        forces: &mut Vec<Vec2>,
+        forces: &mut [Vec2],
         new_dots: &mut Vec<Dot>,
+        events: &mut Vec<SimEvent>,
     ) {
         let dot_i = &self.dots[i];
         let dot_j = &self.dots[j];
@@ -434,12 +621,9 @@ impl GameState {
                 let force = direction * config.repulsion_strength / distance.max(1.0);
                 forces[j] -= force;
             }
-            DotType::Chaser => {
-                if dot_j.dot_type != dot_i.dot_type {
-                    let force = direction * config.attraction_strength * 2.0 / distance.max(1.0);
-                    forces[i] += force;
-                }
-            }
+            // Chaser/Predator steering is applied once per navigator in
+            // `update_navigation`, not per nearby pair here — see that
+            // method for why.
             DotType::Prey => {
                 if dot_j.dot_type == DotType::Predator {
                     let force = direction * config.repulsion_strength * 3.0 / distance.max(1.0);
@@ -469,16 +653,19 @@ impl GameState {
             DotType::Divider => {
                 if dot_i.radius > config.divide_size && self.frame_count % 120 == 0 {
                     // Create offspring
+                    let mut rng = self.rng.borrow_mut();
                     let mut offspring = Dot::new(
-                        dot_i.position.x + thread_rng().gen_range(-10.0..10.0),
-                        dot_i.position.y + thread_rng().gen_range(-10.0..10.0),
+                        dot_i.position.x + rng.gen_range(-10.0..10.0),
+                        dot_i.position.y + rng.gen_range(-10.0..10.0),
                         DotType::Divider,
                     );
                     offspring.velocity = vec2(
-                        thread_rng().gen_range(-2.0..2.0),
-                        thread_rng().gen_range(-2.0..2.0),
+                        rng.gen_range(-2.0..2.0),
+                        rng.gen_range(-2.0..2.0),
                     );
+                    drop(rng);
                     new_dots.push(offspring);
+                    events.push(SimEvent::Split { mass: dot_i.mass });
                 }
             }
             DotType::Bouncer => {
@@ -488,6 +675,15 @@ impl GameState {
                     forces[i] -= force * 0.5;
                 }
             }
+            DotType::Scripted(index) => {
+                let force = self.scripts.call_on_interact(
+                    index,
+                    dot_to_script_map(dot_i),
+                    dot_to_script_map(dot_j),
+                    distance,
+                );
+                forces[i] += force;
+            }
             _ => {}
         }
 
@@ -501,10 +697,263 @@ impl GameState {
                 let force = direction * config.repulsion_strength / distance.max(1.0);
                 forces[i] += force;
             }
+            DotType::Scripted(index) => {
+                let force = self.scripts.call_on_interact(
+                    index,
+                    dot_to_script_map(dot_j),
+                    dot_to_script_map(dot_i),
+                    distance,
+                );
+                forces[j] += force;
+            }
             _ => {}
         }
     }
 
+    /// Finds the nearest living dot of `dot_type` to dot `i`, returning the
+    /// normalized direction toward it and the distance.
+    fn nearest_of_type(&self, i: usize, dot_type: DotType) -> Option<(Vec2, f32)> {
+        let origin = self.dots[i].position;
+        self.dots
+            .iter()
+            .enumerate()
+            .filter(|(k, d)| *k != i && d.alive && d.dot_type == dot_type)
+            .map(|(_, d)| {
+                let distance = origin.distance(d.position);
+                let direction = if distance > 0.1 {
+                    (d.position - origin) / distance
+                } else {
+                    vec2(0.0, 0.0)
+                };
+                (direction, distance)
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// Finds the position of the nearest living dot to `i` whose type
+    /// satisfies `matches_target`, used by navigation to pick a `Chaser`/
+    /// `Predator` dot's pursuit target.
+    fn nearest_target_position(&self, i: usize, mut matches_target: impl FnMut(DotType) -> bool) -> Option<Vec2> {
+        let origin = self.dots[i].position;
+        self.dots
+            .iter()
+            .enumerate()
+            .filter(|(k, d)| *k != i && d.alive && matches_target(d.dot_type))
+            .map(|(_, d)| d.position)
+            .min_by(|a, b| origin.distance(*a).partial_cmp(&origin.distance(*b)).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// Builds a coarse navigation grid for this frame: cells containing an
+    /// `Obstacle` dot, or crowded with `CLUSTER_DENSITY_BLOCK`+ other dots,
+    /// are marked blocked so `Chaser`/`Predator` dots path around them.
+    fn build_nav_grid(&self) -> NavGrid {
+        let mut grid = NavGrid::new(SCREEN_WIDTH, SCREEN_HEIGHT, NAV_CELL_SIZE);
+        let mut density: std::collections::HashMap<(usize, usize), usize> = std::collections::HashMap::new();
+
+        for dot in self.dots.iter().filter(|d| d.alive) {
+            let cell = grid.cell_of(dot.position);
+            if dot.dot_type == DotType::Obstacle {
+                grid.block_cell(cell);
+                continue;
+            }
+            *density.entry(cell).or_insert(0) += 1;
+        }
+
+        for (cell, count) in density {
+            if count >= CLUSTER_DENSITY_BLOCK {
+                grid.block_cell(cell);
+            }
+        }
+
+        grid
+    }
+
+    /// Recomputes each `Chaser`/`Predator` dot's A* path toward its
+    /// nearest target every `NAV_RECOMPUTE_FRAMES` frames (or sooner, if
+    /// the target cell changes) and applies a steering force toward its
+    /// next waypoint. Applied once per navigator here, rather than inside
+    /// `apply_type_forces`'s pairwise loop, since the waypoint direction
+    /// doesn't depend on which other dot happened to be nearby.
+    fn update_navigation(&mut self) {
+        let navigators: Vec<usize> = self
+            .dots
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.alive && matches!(d.dot_type, DotType::Chaser | DotType::Predator))
+            .map(|(i, _)| i)
+            .collect();
+
+        if navigators.is_empty() {
+            return;
+        }
+
+        let grid = self.build_nav_grid();
+
+        for i in navigators {
+            let target = match self.dots[i].dot_type {
+                DotType::Chaser => {
+                    self.nearest_target_position(i, |t| !matches!(t, DotType::Chaser | DotType::Obstacle))
+                }
+                DotType::Predator => {
+                    self.nearest_target_position(i, |t| matches!(t, DotType::Prey | DotType::Classic))
+                }
+                _ => None,
+            };
+
+            let Some(target) = target else {
+                self.dots[i].nav_direction = vec2(0.0, 0.0);
+                continue;
+            };
+
+            let target_cell = grid.cell_of(target);
+            let position = self.dots[i].position;
+            let dot = &mut self.dots[i];
+
+            let stale = dot.nav_recompute_in == 0 || dot.nav_target_cell != Some(target_cell);
+            if stale {
+                dot.nav_path = grid.find_path(position, target).unwrap_or_default();
+                dot.nav_target_cell = Some(target_cell);
+                dot.nav_recompute_in = NAV_RECOMPUTE_FRAMES;
+            } else {
+                dot.nav_recompute_in -= 1;
+            }
+
+            while matches!(dot.nav_path.first(), Some(next) if position.distance(*next) < NAV_CELL_SIZE * 0.5) {
+                dot.nav_path.remove(0);
+            }
+
+            let steer_toward = dot.nav_path.first().copied().unwrap_or(target);
+            dot.nav_direction = (steer_toward - position).normalize_or_zero();
+
+            let strength = match dot.dot_type {
+                DotType::Chaser => self.config.attraction_strength * 2.0,
+                DotType::Predator => self.config.attraction_strength * 1.5,
+                _ => 0.0,
+            };
+            let force = dot.nav_direction * strength;
+            dot.apply_force(force);
+        }
+    }
+
+    /// Builds the sensor vector fed to a `Neural` dot's brain: normalized
+    /// velocity, direction/distance to the nearest predator, nearest prey,
+    /// nearest wall, and current energy.
+    fn neural_inputs(&self, i: usize) -> Vec<f32> {
+        let dot = &self.dots[i];
+        let diag = (SCREEN_WIDTH * SCREEN_WIDTH + SCREEN_HEIGHT * SCREEN_HEIGHT).sqrt();
+
+        let (predator_dir, predator_dist) = self
+            .nearest_of_type(i, DotType::Predator)
+            .unwrap_or((vec2(0.0, 0.0), diag));
+        let (prey_dir, prey_dist) = self
+            .nearest_of_type(i, DotType::Prey)
+            .unwrap_or((vec2(0.0, 0.0), diag));
+
+        let dist_left = dot.position.x;
+        let dist_right = SCREEN_WIDTH - dot.position.x;
+        let dist_top = dot.position.y;
+        let dist_bottom = SCREEN_HEIGHT - dot.position.y;
+        let wall_dist = dist_left.min(dist_right).min(dist_top).min(dist_bottom);
+        let wall_dir = if wall_dist == dist_left {
+            vec2(-1.0, 0.0)
+        } else if wall_dist == dist_right {
+            vec2(1.0, 0.0)
+        } else if wall_dist == dist_top {
+            vec2(0.0, -1.0)
+        } else {
+            vec2(0.0, 1.0)
+        };
+
+        vec![
+            dot.velocity.x / self.config.max_speed,
+            dot.velocity.y / self.config.max_speed,
+            predator_dir.x,
+            predator_dir.y,
+            predator_dist / diag,
+            prey_dir.x,
+            prey_dir.y,
+            prey_dist / diag,
+            wall_dir.x,
+            wall_dir.y,
+            wall_dist / diag,
+            dot.energy / 100.0,
+        ]
+    }
+
+    /// Drives every `Neural` dot's brain forward pass and applies the
+    /// resulting 2-D acceleration as a force.
+    fn apply_neural_forces(&mut self) {
+        let neural_indices: Vec<usize> = self
+            .dots
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.alive && d.dot_type == DotType::Neural && d.brain.is_some())
+            .map(|(i, _)| i)
+            .collect();
+
+        for i in neural_indices {
+            let inputs = self.neural_inputs(i);
+            let output = self.dots[i].brain.as_ref().unwrap().forward(&inputs);
+            let force = vec2(output[0], output[1]) * self.config.attraction_strength;
+            self.dots[i].apply_force(force);
+        }
+    }
+
+    /// Ends the current generation of `Neural` dots: scores every brain by
+    /// fitness (energy gathered + time survived), breeds the next
+    /// generation from the fittest fraction, and respawns them.
+    fn evolve_generation(&mut self) {
+        let candidates: Vec<Candidate> = self
+            .dots
+            .iter()
+            .filter(|d| d.dot_type == DotType::Neural)
+            .filter_map(|d| {
+                d.brain.clone().map(|brain| Candidate {
+                    brain,
+                    fitness: d.energy.max(0.0) + d.age,
+                })
+            })
+            .collect();
+
+        let count = candidates.len().max(1);
+        let mut rng = self.rng.borrow_mut();
+        let brains = Population::breed(
+            candidates,
+            count,
+            0.2,
+            self.config.mut_rate,
+            &NN::default_topology(),
+            &mut *rng,
+        );
+
+        self.dots.retain(|d| d.dot_type != DotType::Neural);
+        for brain in brains {
+            if self.dots.len() < MAX_DOTS {
+                self.dots.push(Dot::random_with_brain(brain, &mut *rng));
+            }
+        }
+    }
+
+    /// Calls each `Scripted` dot's optional `on_update(self, dt)` hook and
+    /// applies whatever force it returns.
+    fn apply_scripted_updates(&mut self, dt: f32) {
+        let scripted_indices: Vec<(usize, usize)> = self
+            .dots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, d)| match d.dot_type {
+                DotType::Scripted(index) if d.alive => Some((i, index)),
+                _ => None,
+            })
+            .collect();
+
+        for (i, index) in scripted_indices {
+            let map = dot_to_script_map(&self.dots[i]);
+            let force = self.scripts.call_on_update(index, map, dt);
+            self.dots[i].apply_force(force);
+        }
+    }
+
     fn update(&mut self, dt: f32) {
         if self.paused {
             return;
@@ -512,70 +961,98 @@ impl GameState {
 
         self.frame_count += 1;
 
+        // Refresh Chaser/Predator pathing and apply their steering forces
+        self.update_navigation();
+
         // Apply interactions between dots
-        self.apply_interactions();
+        let mut events = self.apply_interactions();
+        self.apply_neural_forces();
+        self.apply_scripted_updates(dt);
 
         // Update each dot
         for dot in self.dots.iter_mut() {
-            dot.update(&self.config, dt);
+            events.extend(dot.update(&self.config, dt));
+        }
+
+        let has_neural = self.dots.iter().any(|d| d.dot_type == DotType::Neural);
+        if has_neural && self.population.tick(self.config.generation_length) {
+            self.evolve_generation();
         }
 
         // Game of Life mode (classic cellular automaton in continuous space)
         if self.game_of_life_mode && self.frame_count % 30 == 0 {
-            self.apply_game_of_life_rules();
+            events.extend(self.apply_game_of_life_rules());
+        }
+
+        for event in events {
+            self.audio.handle(event);
         }
     }
 
-    fn apply_game_of_life_rules(&mut self) {
+    fn apply_game_of_life_rules(&mut self) -> Vec<SimEvent> {
         let mut to_add = Vec::new();
         let mut to_remove = Vec::new();
+        let mut events = Vec::new();
+
+        let grid = self.build_spatial_grid();
+        let mut neighbor_counts = vec![0u32; self.dots.len()];
+        for (i, j) in grid.candidate_pairs() {
+            if !self.dots[i].alive
+                || !self.dots[j].alive
+                || self.dots[i].dot_type != DotType::Classic
+                || self.dots[j].dot_type != DotType::Classic
+            {
+                continue;
+            }
+
+            if self.dots[i].distance_to(&self.dots[j]) < self.config.interaction_radius {
+                neighbor_counts[i] += 1;
+                neighbor_counts[j] += 1;
+            }
+        }
 
         for i in 0..self.dots.len() {
             if !self.dots[i].alive || self.dots[i].dot_type != DotType::Classic {
                 continue;
             }
 
-            // Count neighbors within interaction radius
-            let mut neighbors = 0;
-            for j in 0..self.dots.len() {
-                if i != j && self.dots[j].alive && self.dots[j].dot_type == DotType::Classic {
-                    let distance = self.dots[i].distance_to(&self.dots[j]);
-                    if distance < self.config.interaction_radius {
-                        neighbors += 1;
-                    }
-                }
-            }
+            let neighbors = neighbor_counts[i];
 
             // Classic Game of Life rules: 2-3 neighbors survive, 3 creates new
             if neighbors < 2 || neighbors > 3 {
                 to_remove.push(i);
             } else if neighbors == 3 {
                 // Try to create new dot nearby
-                let mut rng = thread_rng();
+                let mut rng = self.rng.borrow_mut();
                 let offset_x = rng.gen_range(-20.0..20.0);
                 let offset_y = rng.gen_range(-20.0..20.0);
+                drop(rng);
                 to_add.push(Dot::new(
                     self.dots[i].position.x + offset_x,
                     self.dots[i].position.y + offset_y,
                     DotType::Classic,
                 ));
+                events.push(SimEvent::GameOfLifeBirth);
             }
         }
 
         // Apply changes
         for &i in to_remove.iter().rev() {
             self.dots[i].alive = false;
+            events.push(SimEvent::GameOfLifeDeath);
         }
         for dot in to_add {
             if self.dots.len() < MAX_DOTS {
                 self.dots.push(dot);
             }
         }
+
+        events
     }
 
-    fn draw(&self) {
+    fn draw(&self, gfx: &mut dyn GameInterface) {
         for dot in &self.dots {
-            dot.draw(self.show_aura);
+            dot.draw(self.show_aura, gfx);
         }
     }
 
@@ -602,70 +1079,158 @@ fn window_conf() -> Conf {
     }
 }
 
-#[macroquad::main(window_conf)]
-async fn main() {
+/// Summary dumped after a headless run: final population split by type,
+/// mean energy across survivors, and how many dots made it to the end.
+struct HeadlessSummary {
+    seed: u64,
+    frames: u32,
+    survivors: usize,
+    mean_energy: f32,
+    counts_by_type: Vec<(DotType, usize)>,
+}
+
+impl std::fmt::Display for HeadlessSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "seed={} frames={} survivors={} mean_energy={:.2}",
+            self.seed, self.frames, self.survivors, self.mean_energy)?;
+        for (dot_type, count) in &self.counts_by_type {
+            writeln!(f, "  {:?}: {}", dot_type, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `frames` ticks of the simulation with no window, input, or
+/// rendering, seeded so the result is fully reproducible, then prints a
+/// summary. Used for regression-testing the physics and Game-of-Life
+/// rules without needing a GPU/display.
+fn run_headless(frames: u32, seed: u64) {
+    let mut game = GameState::new_seeded(seed);
+    let gfx = HeadlessInterface::new(1.0);
+
+    game.seed_random(100, None);
+
+    for _ in 0..frames {
+        game.update(gfx.delta_time());
+    }
+
+    let survivors: Vec<&Dot> = game.dots.iter().filter(|d| d.alive).collect();
+    let mean_energy = if survivors.is_empty() {
+        0.0
+    } else {
+        survivors.iter().map(|d| d.energy).sum::<f32>() / survivors.len() as f32
+    };
+
+    let mut counts_by_type: Vec<(DotType, usize)> = Vec::new();
+    for dot_type in DotType::all_types() {
+        let count = survivors.iter().filter(|d| d.dot_type == dot_type).count();
+        if count > 0 {
+            counts_by_type.push((dot_type, count));
+        }
+    }
+
+    let summary = HeadlessSummary {
+        seed,
+        frames,
+        survivors: survivors.len(),
+        mean_energy,
+        counts_by_type,
+    };
+    print!("{}", summary);
+}
+
+/// Parses `--headless <frames> <seed>` from the process args. Returns
+/// `None` when the flag isn't present, so normal windowed startup is
+/// unaffected.
+fn parse_headless_args(args: &[String]) -> Option<(u32, u64)> {
+    let index = args.iter().position(|a| a == "--headless")?;
+    let frames = args.get(index + 1)?.parse().ok()?;
+    let seed = args.get(index + 2)?.parse().ok()?;
+    Some((frames, seed))
+}
+
+async fn run_windowed() {
     let mut game = GameState::new();
+    let mut gfx = MacroquadInterface;
     let mut selected_type_index = 0;
     let all_types = DotType::all_types();
 
     // Try to load config
     let _ = game.load_config("dotgame_config.json");
 
+    // Load sound effects (no-op when the `audio` feature is disabled)
+    game.audio = Audio::load("assets/sounds").await;
+
     // Seed with some initial dots
     game.seed_random(50, None);
 
     loop {
         // Input handling
-        if is_key_pressed(KeyCode::Space) {
+        if gfx.key_pressed(KeyCode::Space) {
             game.paused = !game.paused;
         }
 
-        if is_key_pressed(KeyCode::A) {
+        if gfx.key_pressed(KeyCode::A) {
             game.show_aura = !game.show_aura;
         }
 
-        if is_key_pressed(KeyCode::G) {
+        if gfx.key_pressed(KeyCode::G) {
             game.game_of_life_mode = !game.game_of_life_mode;
         }
 
-        if is_key_pressed(KeyCode::C) {
+        if gfx.key_pressed(KeyCode::C) {
             game.dots.clear();
         }
 
-        if is_key_pressed(KeyCode::R) {
+        if gfx.key_pressed(KeyCode::R) {
             game.seed_random(100, Some(thread_rng().gen()));
         }
 
-        if is_key_pressed(KeyCode::S) {
+        if gfx.key_pressed(KeyCode::S) {
             let _ = game.save_config("dotgame_config.json");
         }
 
-        if is_key_pressed(KeyCode::L) {
+        if gfx.key_pressed(KeyCode::L) {
             let _ = game.load_config("dotgame_config.json");
         }
 
         // Cycle through dot types
-        if is_key_pressed(KeyCode::Tab) {
+        if gfx.key_pressed(KeyCode::Tab) {
             selected_type_index = (selected_type_index + 1) % all_types.len();
             game.selected_type = all_types[selected_type_index];
         }
 
-        if is_key_pressed(KeyCode::Key1) {
+        // Cycle through loaded .rhai scripted types
+        if gfx.key_pressed(KeyCode::T) && !game.scripts.types.is_empty() {
+            let next_index = match game.selected_type {
+                DotType::Scripted(index) => (index + 1) % game.scripts.types.len(),
+                _ => 0,
+            };
+            game.selected_type = DotType::Scripted(next_index);
+        }
+
+        // Hot-reload .rhai scripts from disk
+        if gfx.key_pressed(KeyCode::F5) {
+            let remap = game.scripts.reload();
+            game.remap_scripted_types(&remap);
+        }
+
+        if gfx.key_pressed(KeyCode::Key1) {
             game.config.max_speed = (game.config.max_speed + 1.0).min(20.0);
         }
-        if is_key_pressed(KeyCode::Key2) {
+        if gfx.key_pressed(KeyCode::Key2) {
             game.config.max_speed = (game.config.max_speed - 1.0).max(1.0);
         }
 
         // Mouse input for placing dots
-        if is_mouse_button_down(MouseButton::Left) {
-            let (x, y) = mouse_position();
+        if gfx.mouse_button_down(MouseButton::Left) {
+            let (x, y) = gfx.mouse_position();
             game.add_dot(x, y);
         }
 
-        if is_mouse_button_down(MouseButton::Right) {
+        if gfx.mouse_button_down(MouseButton::Right) {
             // Remove dots near mouse
-            let (x, y) = mouse_position();
+            let (x, y) = gfx.mouse_position();
             for dot in &mut game.dots {
                 if dot.position.distance(vec2(x, y)) < 20.0 {
                     dot.alive = false;
@@ -677,15 +1242,15 @@ async fn main() {
         game.update(1.0);
 
         // Rendering
-        clear_background(BLACK);
+        gfx.clear();
 
-        game.draw();
+        game.draw(&mut gfx);
 
         // Draw UI
         let ui_text = format!(
-            "FPS: {:.0} | Dots: {} | Type: {:?} (Tab to change)\n\
+            "FPS: {:.0} | Dots: {} | Type: {:?} (Tab to change, T for scripts)\n\
              Space: Pause {} | A: Aura {} | G: Game of Life {} | C: Clear | R: Random\n\
-             1/2: Speed +/- | S: Save Config | L: Load Config\n\
+             1/2: Speed +/- | S: Save Config | L: Load Config | F5: Reload Scripts\n\
              Left Click: Add | Right Click: Remove",
             get_fps(),
             game.dots.iter().filter(|d| d.alive).count(),
@@ -695,12 +1260,92 @@ async fn main() {
             if game.game_of_life_mode { "✓" } else { "✗" },
         );
 
-        draw_text(&ui_text, 10.0, 20.0, 20.0, WHITE);
+        gfx.draw_text(&ui_text, 10.0, 20.0, 20.0, WHITE);
 
         // Draw selected type indicator
-        draw_circle(SCREEN_WIDTH - 50.0, 50.0, 20.0, game.selected_type.color());
-        draw_text("Selected", SCREEN_WIDTH - 100.0, 80.0, 16.0, WHITE);
+        let selected_color = match game.selected_type {
+            DotType::Scripted(index) => game
+                .scripts
+                .types
+                .get(index)
+                .map(|t| t.color)
+                .unwrap_or(WHITE),
+            other => other.color(),
+        };
+        gfx.draw_circle(SCREEN_WIDTH - 50.0, 50.0, 20.0, selected_color);
+        gfx.draw_text("Selected", SCREEN_WIDTH - 100.0, 80.0, 16.0, WHITE);
 
         next_frame().await;
     }
 }
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if let Some((frames, seed)) = parse_headless_args(&args) {
+        run_headless(frames, seed);
+        return;
+    }
+
+    macroquad::Window::from_config(window_conf(), run_windowed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_seeded(seed: u64, frames: u32) -> GameState {
+        let mut game = GameState::new_seeded(seed);
+        let gfx = HeadlessInterface::new(1.0);
+        game.seed_random(50, None);
+        for _ in 0..frames {
+            game.update(gfx.delta_time());
+        }
+        game
+    }
+
+    #[test]
+    fn headless_run_is_deterministic() {
+        let a = run_seeded(42, 200);
+        let b = run_seeded(42, 200);
+
+        let positions_a: Vec<Vec2> = a.dots.iter().map(|d| d.position).collect();
+        let positions_b: Vec<Vec2> = b.dots.iter().map(|d| d.position).collect();
+        assert_eq!(positions_a, positions_b);
+    }
+
+    #[test]
+    fn dot_dies_when_energy_runs_out() {
+        let mut dot = Dot::new(100.0, 100.0, DotType::Classic);
+        dot.energy = 0.05;
+        let config = DotConfig::default();
+        dot.update(&config, 1.0);
+        assert!(!dot.alive);
+    }
+
+    #[test]
+    fn dot_bounces_off_left_wall() {
+        let config = DotConfig::default();
+        let mut dot = Dot::new(DOT_RADIUS + 1.0, 100.0, DotType::Classic);
+        dot.velocity = vec2(-5.0, 0.0);
+
+        let events = dot.update(&config, 1.0);
+
+        assert_eq!(dot.position.x, dot.radius);
+        assert!(dot.velocity.x > 0.0, "velocity should flip away from the wall");
+        assert!(matches!(events[0], SimEvent::WallBounce { .. }));
+    }
+
+    #[test]
+    fn game_of_life_rules_kill_underpopulated_dots() {
+        let mut game = GameState::new();
+        game.game_of_life_mode = true;
+        game.add_dot(500.0, 500.0);
+        game.dots[0].dot_type = DotType::Classic;
+
+        let events = game.apply_game_of_life_rules();
+
+        assert!(!game.dots[0].alive);
+        assert!(events.iter().any(|e| matches!(e, SimEvent::GameOfLifeDeath)));
+    }
+}
@@ -0,0 +1,124 @@
+use nalgebra::DMatrix;
+use rand_distr::{Distribution, StandardNormal};
+
+/// Number of sensor inputs fed to a `Neural` dot's brain each tick:
+/// normalized velocity (2), direction+distance to nearest predator (3),
+/// nearest prey (3), nearest wall (3), and current energy (1).
+pub const NN_INPUTS: usize = 12;
+/// Output is a 2-D acceleration vector.
+pub const NN_OUTPUTS: usize = 2;
+
+/// A small feed-forward network driving a `DotType::Neural` dot's movement.
+/// For consecutive layer sizes `(curr, next)` the weight matrix is
+/// `next x (curr + 1)`, where the extra column is a bias.
+#[derive(Debug, Clone)]
+pub struct NN {
+    pub config: Vec<usize>,
+    pub weights: Vec<DMatrix<f32>>,
+}
+
+impl NN {
+    /// Builds a network for the given layer sizes (including the input and
+    /// output layers), with every weight drawn from a standard normal
+    /// distribution via `rng`, so a seeded `StdRng` makes the brain
+    /// reproducible.
+    pub fn new(config: Vec<usize>, rng: &mut impl ::rand::Rng) -> Self {
+        let weights = config
+            .windows(2)
+            .map(|pair| {
+                let (curr, next) = (pair[0], pair[1]);
+                DMatrix::from_fn(next, curr + 1, |_, _| StandardNormal.sample(rng))
+            })
+            .collect();
+        Self { config, weights }
+    }
+
+    /// Default topology: sensor inputs -> one hidden layer -> 2-D
+    /// acceleration output.
+    pub fn default_topology() -> Vec<usize> {
+        vec![NN_INPUTS, 8, NN_OUTPUTS]
+    }
+
+    /// Appends a bias of `1.0` to the input, multiplies through each
+    /// layer's weight matrix, and applies ReLU on hidden layers / tanh on
+    /// the output layer.
+    pub fn forward(&self, input: &[f32]) -> Vec<f32> {
+        let mut activations = input.to_vec();
+        let last_layer = self.weights.len() - 1;
+
+        for (layer_index, weights) in self.weights.iter().enumerate() {
+            let mut biased = activations;
+            biased.push(1.0);
+            let input_vec = DMatrix::from_vec(biased.len(), 1, biased);
+            let output = weights * input_vec;
+
+            activations = output
+                .iter()
+                .map(|&x| if layer_index == last_layer { x.tanh() } else { x.max(0.0) })
+                .collect();
+        }
+
+        activations
+    }
+
+    /// Breeds offspring from two parents by picking each weight
+    /// element-wise from one parent or the other at random, via `rng`.
+    pub fn crossover(&self, other: &NN, rng: &mut impl ::rand::Rng) -> NN {
+        let weights = self
+            .weights
+            .iter()
+            .zip(other.weights.iter())
+            .map(|(a, b)| DMatrix::from_fn(a.nrows(), a.ncols(), |r, c| {
+                if rng.gen_bool(0.5) { a[(r, c)] } else { b[(r, c)] }
+            }))
+            .collect();
+        NN { config: self.config.clone(), weights }
+    }
+
+    /// Mutates every weight with probability `0.1`, adding
+    /// `StandardNormal * mut_rate` noise drawn from `rng`.
+    pub fn mutate(&mut self, mut_rate: f32, rng: &mut impl ::rand::Rng) {
+        for matrix in &mut self.weights {
+            for value in matrix.iter_mut() {
+                if rng.gen_bool(0.1) {
+                    let noise: f32 = StandardNormal.sample(rng);
+                    *value += noise * mut_rate;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::SeedableRng;
+    use ::rand::rngs::StdRng;
+
+    #[test]
+    fn forward_output_matches_output_layer_size() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let nn = NN::new(NN::default_topology(), &mut rng);
+        let output = nn.forward(&[0.0; NN_INPUTS]);
+        assert_eq!(output.len(), NN_OUTPUTS);
+    }
+
+    #[test]
+    fn forward_output_is_bounded_by_tanh() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let nn = NN::new(NN::default_topology(), &mut rng);
+        let output = nn.forward(&[1000.0; NN_INPUTS]);
+        assert!(output.iter().all(|&v| (-1.0..=1.0).contains(&v)));
+    }
+
+    #[test]
+    fn same_seed_produces_identical_networks() {
+        let mut rng_a = StdRng::seed_from_u64(7);
+        let mut rng_b = StdRng::seed_from_u64(7);
+        let a = NN::new(NN::default_topology(), &mut rng_a);
+        let b = NN::new(NN::default_topology(), &mut rng_b);
+
+        let inputs = vec![0.5; NN_INPUTS];
+        assert_eq!(a.forward(&inputs), b.forward(&inputs));
+    }
+}
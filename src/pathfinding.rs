@@ -0,0 +1,197 @@
+use macroquad::prelude::{vec2, Vec2};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Width/height of one navigation cell. Coarser than `SpatialGrid`'s cells
+/// since this grid is walked by A* rather than scanned for neighbor pairs.
+pub const NAV_CELL_SIZE: f32 = 40.0;
+
+type Cell = (usize, usize);
+
+/// Coarse occupancy grid over the playfield used for `Chaser`/`Predator`
+/// navigation: cells are marked blocked by user-placed `Obstacle` dots or
+/// by dense clusters of other dots, then routed around with A*.
+pub struct NavGrid {
+    cols: usize,
+    rows: usize,
+    cell_size: f32,
+    blocked: Vec<bool>,
+}
+
+impl NavGrid {
+    pub fn new(width: f32, height: f32, cell_size: f32) -> Self {
+        let cols = (width / cell_size).ceil().max(1.0) as usize;
+        let rows = (height / cell_size).ceil().max(1.0) as usize;
+        Self { cols, rows, cell_size, blocked: vec![false; cols * rows] }
+    }
+
+    pub fn cell_of(&self, pos: Vec2) -> Cell {
+        let col = ((pos.x / self.cell_size) as isize).clamp(0, self.cols as isize - 1);
+        let row = ((pos.y / self.cell_size) as isize).clamp(0, self.rows as isize - 1);
+        (col as usize, row as usize)
+    }
+
+    pub fn block_cell(&mut self, cell: Cell) {
+        let index = self.index(cell);
+        self.blocked[index] = true;
+    }
+
+    pub fn is_blocked(&self, cell: Cell) -> bool {
+        self.blocked[self.index(cell)]
+    }
+
+    fn index(&self, cell: Cell) -> usize {
+        cell.1 * self.cols + cell.0
+    }
+
+    fn cell_center(&self, cell: Cell) -> Vec2 {
+        vec2((cell.0 as f32 + 0.5) * self.cell_size, (cell.1 as f32 + 0.5) * self.cell_size)
+    }
+
+    fn neighbors(&self, cell: Cell) -> Vec<Cell> {
+        let (col, row) = (cell.0 as isize, cell.1 as isize);
+        let mut out = Vec::with_capacity(8);
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let (nc, nr) = (col + dx, row + dy);
+                if nc < 0 || nr < 0 || nc as usize >= self.cols || nr as usize >= self.rows {
+                    continue;
+                }
+                let neighbor = (nc as usize, nr as usize);
+                if !self.is_blocked(neighbor) {
+                    out.push(neighbor);
+                }
+            }
+        }
+        out
+    }
+
+    /// Finds a path of waypoint centers from `start` to `goal` with
+    /// 8-connected A* and a Euclidean heuristic. Returns `None` when
+    /// `start`/`goal` share a cell or no route avoids the blocked cells.
+    pub fn find_path(&self, start: Vec2, goal: Vec2) -> Option<Vec<Vec2>> {
+        let start_cell = self.cell_of(start);
+        let goal_cell = self.cell_of(goal);
+
+        if start_cell == goal_cell || self.is_blocked(goal_cell) {
+            return None;
+        }
+
+        let heuristic = |cell: Cell| self.cell_center(cell).distance(self.cell_center(goal_cell));
+
+        let mut open_set = BinaryHeap::new();
+        let mut came_from: HashMap<Cell, Cell> = HashMap::new();
+        let mut g_score: HashMap<Cell, f32> = HashMap::new();
+
+        g_score.insert(start_cell, 0.0);
+        open_set.push(ScoredCell { cell: start_cell, f_score: heuristic(start_cell) });
+
+        while let Some(ScoredCell { cell, .. }) = open_set.pop() {
+            if cell == goal_cell {
+                return Some(self.reconstruct_path(&came_from, cell));
+            }
+
+            let current_g = g_score[&cell];
+            for neighbor in self.neighbors(cell) {
+                let step_cost = self.cell_center(cell).distance(self.cell_center(neighbor));
+                let tentative_g = current_g + step_cost;
+                if tentative_g < *g_score.get(&neighbor).unwrap_or(&f32::INFINITY) {
+                    came_from.insert(neighbor, cell);
+                    g_score.insert(neighbor, tentative_g);
+                    open_set.push(ScoredCell { cell: neighbor, f_score: tentative_g + heuristic(neighbor) });
+                }
+            }
+        }
+
+        None
+    }
+
+    fn reconstruct_path(&self, came_from: &HashMap<Cell, Cell>, mut cell: Cell) -> Vec<Vec2> {
+        let mut cells = vec![cell];
+        while let Some(&prev) = came_from.get(&cell) {
+            cell = prev;
+            cells.push(cell);
+        }
+        cells.reverse();
+        cells.into_iter().skip(1).map(|c| self.cell_center(c)).collect()
+    }
+}
+
+/// Open-set entry ordered by ascending `f_score`; `BinaryHeap` is a
+/// max-heap, so `Ord` is reversed to make it behave like A*'s min-heap.
+struct ScoredCell {
+    cell: Cell,
+    f_score: f32,
+}
+
+impl PartialEq for ScoredCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.f_score == other.f_score
+    }
+}
+
+impl Eq for ScoredCell {}
+
+impl PartialOrd for ScoredCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_score.partial_cmp(&self.f_score).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_path_in_an_open_grid() {
+        let grid = NavGrid::new(400.0, 400.0, NAV_CELL_SIZE);
+        let path = grid.find_path(vec2(10.0, 10.0), vec2(300.0, 300.0));
+        assert!(path.is_some());
+        assert!(!path.unwrap().is_empty());
+    }
+
+    #[test]
+    fn returns_none_when_start_and_goal_share_a_cell() {
+        let grid = NavGrid::new(400.0, 400.0, NAV_CELL_SIZE);
+        let path = grid.find_path(vec2(10.0, 10.0), vec2(15.0, 15.0));
+        assert!(path.is_none());
+    }
+
+    #[test]
+    fn returns_none_when_goal_is_blocked() {
+        let mut grid = NavGrid::new(400.0, 400.0, NAV_CELL_SIZE);
+        let goal = vec2(300.0, 300.0);
+        let goal_cell = grid.cell_of(goal);
+        grid.block_cell(goal_cell);
+
+        assert!(grid.find_path(vec2(10.0, 10.0), goal).is_none());
+    }
+
+    #[test]
+    fn routes_around_a_blocked_column() {
+        let mut grid = NavGrid::new(400.0, 400.0, NAV_CELL_SIZE);
+        let start = vec2(10.0, 200.0);
+        let goal = vec2(300.0, 200.0);
+
+        // Block every cell in the column between start and goal except one
+        // gap, so a route must exist but can't go in a straight line.
+        let col = grid.cell_of(vec2(150.0, 200.0)).0;
+        for row in 0..10 {
+            if row != 3 {
+                grid.block_cell((col, row));
+            }
+        }
+
+        let path = grid.find_path(start, goal).expect("a route around the gap should exist");
+        assert!(!path.is_empty());
+    }
+}
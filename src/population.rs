@@ -0,0 +1,71 @@
+use crate::neural::NN;
+
+/// A `Neural` dot's genome plus the fitness it accumulated (energy
+/// gathered + time survived) before its generation ended.
+pub struct Candidate {
+    pub brain: NN,
+    pub fitness: f32,
+}
+
+/// Evolves a pool of `NN` brains across fixed-length generations. At the
+/// end of each generation the top fraction of candidates by fitness
+/// become parents, and the next generation is bred from them via
+/// crossover + mutation.
+pub struct Population {
+    pub generation: u32,
+    ticks_this_generation: u32,
+}
+
+impl Population {
+    pub fn new() -> Self {
+        Self { generation: 0, ticks_this_generation: 0 }
+    }
+
+    /// Advances the generation clock by one tick. Returns `true` once
+    /// `generation_length` ticks have elapsed, signalling it's time to
+    /// breed the next generation.
+    pub fn tick(&mut self, generation_length: u32) -> bool {
+        self.ticks_this_generation += 1;
+        if self.ticks_this_generation >= generation_length.max(1) {
+            self.ticks_this_generation = 0;
+            self.generation += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Breeds `count` offspring brains from the fittest fraction of
+    /// `candidates`, drawing all parent selection/crossover/mutation
+    /// randomness from `rng` so a seeded `StdRng` makes evolution
+    /// reproducible. With no candidates to breed from (e.g. generation 0),
+    /// falls back to fresh random brains of the given `topology`.
+    pub fn breed(
+        mut candidates: Vec<Candidate>,
+        count: usize,
+        survival_fraction: f32,
+        mut_rate: f32,
+        topology: &[usize],
+        rng: &mut impl ::rand::Rng,
+    ) -> Vec<NN> {
+        if candidates.is_empty() {
+            return (0..count).map(|_| NN::new(topology.to_vec(), rng)).collect();
+        }
+
+        candidates.sort_by(|a, b| {
+            b.fitness.partial_cmp(&a.fitness).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let keep = ((candidates.len() as f32 * survival_fraction).ceil() as usize).max(1);
+        let parents: Vec<NN> = candidates.into_iter().take(keep).map(|c| c.brain).collect();
+
+        (0..count)
+            .map(|_| {
+                let a = &parents[rng.gen_range(0..parents.len())];
+                let b = &parents[rng.gen_range(0..parents.len())];
+                let mut child = a.crossover(b, rng);
+                child.mutate(mut_rate, rng);
+                child
+            })
+            .collect()
+    }
+}
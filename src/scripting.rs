@@ -0,0 +1,155 @@
+use macroquad::prelude::{vec2, Color, Vec2};
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single compiled `.rhai` behavior: the script's declared name and
+/// color, plus its compiled AST so `on_interact`/`on_update` can be called
+/// without re-parsing every frame.
+pub struct ScriptedType {
+    pub name: String,
+    pub color: Color,
+    ast: AST,
+}
+
+/// Loads `.rhai` scripts from a directory and exposes each one as a
+/// `DotType::Scripted(index)` behavior. Each script must define:
+/// - `fn name() -> String`
+/// - `fn color()` returning an `[r, g, b]` array of floats (0.0-1.0)
+/// - `fn on_interact(self, other, distance)` returning a `#{x: .., y: ..}` force map
+/// - optionally `fn on_update(self, dt)` returning the same kind of map
+pub struct ScriptEngine {
+    engine: Engine,
+    dir: PathBuf,
+    pub types: Vec<ScriptedType>,
+}
+
+impl ScriptEngine {
+    /// Compiles every `.rhai` file in `dir`. Missing directories yield an
+    /// empty registry rather than an error, so running without a
+    /// `scripts/` folder is a no-op.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Self {
+        let mut engine = Self {
+            engine: Engine::new(),
+            dir: dir.as_ref().to_path_buf(),
+            types: Vec::new(),
+        };
+        engine.reload();
+        engine
+    }
+
+    /// Recompiles every script under `self.dir`, replacing the registry in
+    /// place (files sorted by path so reload order is deterministic rather
+    /// than whatever `read_dir` happens to hand back). Bound to a keypress
+    /// so designers can tweak `.rhai` files and see the change without
+    /// restarting.
+    ///
+    /// Returns the old registry's `old_index -> new_index` mapping, keyed
+    /// by script name, so callers can repoint any `DotType::Scripted(old_index)`
+    /// already placed in the world at the same script's new slot — without
+    /// this, a reload that adds/removes/renames a script would silently
+    /// hand existing dots someone else's `on_interact`/`on_update`.
+    pub fn reload(&mut self) -> Vec<Option<usize>> {
+        let old_names: Vec<String> = self.types.iter().map(|t| t.name.clone()).collect();
+        self.types.clear();
+
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return old_names.iter().map(|_| None).collect();
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("rhai"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let Ok(ast) = self.engine.compile_file(path.clone()) else {
+                continue;
+            };
+
+            let mut scope = Scope::new();
+            let name = self
+                .engine
+                .call_fn::<String>(&mut scope, &ast, "name", ())
+                .unwrap_or_else(|_| path.display().to_string());
+            let (r, g, b) = self
+                .engine
+                .call_fn::<rhai::Array>(&mut scope, &ast, "color", ())
+                .ok()
+                .and_then(|rgb| {
+                    let c: Vec<f32> = rgb.iter().filter_map(|v| v.as_float().ok()).map(|v| v as f32).collect();
+                    (c.len() == 3).then(|| (c[0], c[1], c[2]))
+                })
+                .unwrap_or((1.0, 1.0, 1.0));
+
+            self.types.push(ScriptedType {
+                name,
+                color: Color::new(r, g, b, 1.0),
+                ast,
+            });
+        }
+
+        old_names
+            .iter()
+            .map(|name| self.types.iter().position(|t| &t.name == name))
+            .collect()
+    }
+
+    /// Calls `on_interact(self, other, distance)` for the script at
+    /// `index`, returning the force it reports (or a zero vector if the
+    /// call fails, so a broken script degrades gracefully instead of
+    /// panicking the simulation).
+    pub fn call_on_interact(
+        &self,
+        index: usize,
+        me: Map,
+        other: Map,
+        distance: f32,
+    ) -> Vec2 {
+        let Some(script) = self.types.get(index) else {
+            return vec2(0.0, 0.0);
+        };
+
+        let mut scope = Scope::new();
+        let result = self.engine.call_fn::<Map>(
+            &mut scope,
+            &script.ast,
+            "on_interact",
+            (me, other, distance as f64),
+        );
+
+        force_from_map(result)
+    }
+
+    /// Calls the optional `on_update(self, dt)` hook for the script at
+    /// `index`. Scripts that don't define it simply contribute no force.
+    pub fn call_on_update(&self, index: usize, me: Map, dt: f32) -> Vec2 {
+        let Some(script) = self.types.get(index) else {
+            return vec2(0.0, 0.0);
+        };
+
+        let mut scope = Scope::new();
+        let result =
+            self.engine
+                .call_fn::<Map>(&mut scope, &script.ast, "on_update", (me, dt as f64));
+
+        force_from_map(result)
+    }
+}
+
+fn dynamic_to_f64(value: Option<&Dynamic>) -> f64 {
+    value.cloned().and_then(|d| d.as_float().ok()).unwrap_or(0.0)
+}
+
+fn force_from_map(result: Result<Map, Box<rhai::EvalAltResult>>) -> Vec2 {
+    match result {
+        Ok(map) => {
+            let x = dynamic_to_f64(map.get("x"));
+            let y = dynamic_to_f64(map.get("y"));
+            vec2(x as f32, y as f32)
+        }
+        Err(_) => vec2(0.0, 0.0),
+    }
+}
@@ -0,0 +1,134 @@
+use macroquad::prelude::Vec2;
+
+/// Uniform grid bucketing dot indices by position, used to cut neighbor
+/// queries down from O(n^2) to roughly linear by only checking the 3x3
+/// block of cells around each dot instead of every other dot.
+pub struct SpatialGrid {
+    cell_size: f32,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl SpatialGrid {
+    /// Builds an empty grid sized to cover `(width, height)` with square
+    /// cells `cell_size` wide (clamped up to at least 1.0). The caller picks
+    /// `cell_size` to cover the largest interaction radius in play.
+    pub fn new(width: f32, height: f32, cell_size: f32) -> Self {
+        let cell_size = cell_size.max(1.0);
+        let cols = (width / cell_size).ceil() as usize + 1;
+        let rows = (height / cell_size).ceil() as usize + 1;
+        Self {
+            cell_size,
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+        }
+    }
+
+    fn cell_coords(&self, position: Vec2) -> (usize, usize) {
+        let cx = (position.x / self.cell_size).floor().max(0.0) as usize;
+        let cy = (position.y / self.cell_size).floor().max(0.0) as usize;
+        (cx.min(self.cols - 1), cy.min(self.rows - 1))
+    }
+
+    fn clear(&mut self) {
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+    }
+
+    /// Rebuilds the grid from scratch for this frame's dot positions, each
+    /// registered in its single containing cell. `candidate_pairs` already
+    /// widens its search to the surrounding 3x3 block, and the caller sizes
+    /// `cell_size` to cover the largest dot in play (see
+    /// `GameState::build_spatial_grid`), so a dot's own cell is never
+    /// narrower than its radius and no extra spreading is needed here.
+    pub fn rebuild(&mut self, positions: &[(Vec2, f32)]) {
+        self.clear();
+
+        for (index, &(position, _radius)) in positions.iter().enumerate() {
+            let (cx, cy) = self.cell_coords(position);
+            self.cells[cy * self.cols + cx].push(index);
+        }
+    }
+
+    /// Returns every unordered pair of indices that share, or are adjacent
+    /// to, a cell, with duplicates removed so each pair is visited once.
+    pub fn candidate_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+
+        for cy in 0..self.rows {
+            for cx in 0..self.cols {
+                let (i, j) = self.neighbor_block_bounds(cx, cy);
+                let mut block: Vec<usize> = Vec::new();
+                for by in i.0..=i.1 {
+                    for bx in j.0..=j.1 {
+                        block.extend_from_slice(&self.cells[by * self.cols + bx]);
+                    }
+                }
+                block.sort_unstable();
+                block.dedup();
+
+                let here = &self.cells[cy * self.cols + cx];
+                for &a in here {
+                    for &b in &block {
+                        if a < b {
+                            pairs.push((a, b));
+                        }
+                    }
+                }
+            }
+        }
+
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+    }
+
+    fn neighbor_block_bounds(&self, cx: usize, cy: usize) -> ((usize, usize), (usize, usize)) {
+        let y0 = cy.saturating_sub(1);
+        let y1 = (cy + 1).min(self.rows - 1);
+        let x0 = cx.saturating_sub(1);
+        let x1 = (cx + 1).min(self.cols - 1);
+        ((y0, y1), (x0, x1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidate_pairs_has_no_duplicates_or_self_pairs() {
+        let mut grid = SpatialGrid::new(200.0, 200.0, 20.0);
+        grid.rebuild(&[
+            (Vec2::new(10.0, 10.0), 5.0),
+            (Vec2::new(12.0, 11.0), 5.0),
+            (Vec2::new(150.0, 150.0), 5.0),
+        ]);
+
+        let pairs = grid.candidate_pairs();
+        let mut seen = std::collections::HashSet::new();
+        for &(a, b) in &pairs {
+            assert_ne!(a, b);
+            assert!(seen.insert((a, b)), "pair ({a}, {b}) reported more than once");
+        }
+    }
+
+    #[test]
+    fn candidate_pairs_finds_dots_sharing_a_cell() {
+        let mut grid = SpatialGrid::new(200.0, 200.0, 20.0);
+        grid.rebuild(&[(Vec2::new(10.0, 10.0), 5.0), (Vec2::new(12.0, 11.0), 5.0)]);
+
+        assert_eq!(grid.candidate_pairs(), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn candidate_pairs_excludes_far_apart_dots() {
+        let mut grid = SpatialGrid::new(200.0, 200.0, 20.0);
+        grid.rebuild(&[(Vec2::new(10.0, 10.0), 5.0), (Vec2::new(190.0, 190.0), 5.0)]);
+
+        assert!(grid.candidate_pairs().is_empty());
+    }
+}